@@ -0,0 +1,561 @@
+use serde::Deserialize;
+
+// time zone for .daily() and .time_zone()
+
+#[derive(Debug, Clone, Copy)]
+pub enum TimeZone {
+    AmericaAnchorage,
+    AmericaLosAngeles,
+    AmericaDenver,
+    AmericaChicago,
+    AmericaNewYork,
+    AmericaSaoPaulo,
+    GMT0,
+    Auto,
+    EuropeLondon,
+    EuropeBerlin,
+    EuropeMoscow,
+    AfricaCairo,
+    AsiaBangkok,
+    AsiaSingapore,
+    AsiaTokyo,
+    AustraliaSydney,
+    PacificAuckland,
+}
+
+// units for .temperature_unit(), .wind_speed_unit() and .precipitation_unit()
+
+#[derive(Debug, Clone, Copy)]
+pub enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum WindSpeedUnit {
+    Kmh,
+    Ms,
+    Mph,
+    Kn,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PrecipitationUnit {
+    Mm,
+    Inch,
+}
+
+// convenience shortcut for .units()
+
+#[derive(Debug, Clone, Copy)]
+pub enum UnitSystem {
+    Metric,
+    Imperial,
+}
+
+// individual hourly variables for .hourly_variables()
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HourlyVariable {
+    Temperature2m,
+    Relativehumidity2m,
+    Dewpoint2m,
+    ApparentTemperature,
+    PrecipitationProbability,
+    Precipitation,
+    Rain,
+    Showers,
+    Snowfall,
+    SnowDepth,
+    Weathercode,
+    PressureMsl,
+    SurfacePressure,
+    Cloudcover,
+    CloudcoverLow,
+    CloudcoverMid,
+    CloudcoverHigh,
+    Visibility,
+    Evapotranspiration,
+    Et0FaoEvapotranspiration,
+    VaporPressureDeficit,
+    Windspeed10m,
+    Windspeed80m,
+    Windspeed120m,
+    Windspeed180m,
+    Winddirection10m,
+    Winddirection80m,
+    Winddirection120m,
+    Winddirection180m,
+    Windgusts10m,
+    Temperature80m,
+    Temperature120m,
+    Temperature180m,
+    SoilTemperature0cm,
+    SoilTemperature6cm,
+    SoilTemperature18cm,
+    SoilTemperature54cm,
+    SoilMoisture01cm,
+    SoilMoisture13cm,
+    SoilMoisture39cm,
+    SoilMoisture927cm,
+    SoilMoisture2781cm,
+}
+
+impl HourlyVariable {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HourlyVariable::Temperature2m => "temperature_2m",
+            HourlyVariable::Relativehumidity2m => "relativehumidity_2m",
+            HourlyVariable::Dewpoint2m => "dewpoint_2m",
+            HourlyVariable::ApparentTemperature => "apparent_temperature",
+            HourlyVariable::PrecipitationProbability => "precipitation_probability",
+            HourlyVariable::Precipitation => "precipitation",
+            HourlyVariable::Rain => "rain",
+            HourlyVariable::Showers => "showers",
+            HourlyVariable::Snowfall => "snowfall",
+            HourlyVariable::SnowDepth => "snow_depth",
+            HourlyVariable::Weathercode => "weathercode",
+            HourlyVariable::PressureMsl => "pressure_msl",
+            HourlyVariable::SurfacePressure => "surface_pressure",
+            HourlyVariable::Cloudcover => "cloudcover",
+            HourlyVariable::CloudcoverLow => "cloudcover_low",
+            HourlyVariable::CloudcoverMid => "cloudcover_mid",
+            HourlyVariable::CloudcoverHigh => "cloudcover_high",
+            HourlyVariable::Visibility => "visibility",
+            HourlyVariable::Evapotranspiration => "evapotranspiration",
+            HourlyVariable::Et0FaoEvapotranspiration => "et0_fao_evapotranspiration",
+            HourlyVariable::VaporPressureDeficit => "vapor_pressure_deficit",
+            HourlyVariable::Windspeed10m => "windspeed_10m",
+            HourlyVariable::Windspeed80m => "windspeed_80m",
+            HourlyVariable::Windspeed120m => "windspeed_120m",
+            HourlyVariable::Windspeed180m => "windspeed_180m",
+            HourlyVariable::Winddirection10m => "winddirection_10m",
+            HourlyVariable::Winddirection80m => "winddirection_80m",
+            HourlyVariable::Winddirection120m => "winddirection_120m",
+            HourlyVariable::Winddirection180m => "winddirection_180m",
+            HourlyVariable::Windgusts10m => "windgusts_10m",
+            HourlyVariable::Temperature80m => "temperature_80m",
+            HourlyVariable::Temperature120m => "temperature_120m",
+            HourlyVariable::Temperature180m => "temperature_180m",
+            HourlyVariable::SoilTemperature0cm => "soil_temperature_0cm",
+            HourlyVariable::SoilTemperature6cm => "soil_temperature_6cm",
+            HourlyVariable::SoilTemperature18cm => "soil_temperature_18cm",
+            HourlyVariable::SoilTemperature54cm => "soil_temperature_54cm",
+            HourlyVariable::SoilMoisture01cm => "soil_moisture_0_1cm",
+            HourlyVariable::SoilMoisture13cm => "soil_moisture_1_3cm",
+            HourlyVariable::SoilMoisture39cm => "soil_moisture_3_9cm",
+            HourlyVariable::SoilMoisture927cm => "soil_moisture_9_27cm",
+            HourlyVariable::SoilMoisture2781cm => "soil_moisture_27_81cm",
+        }
+    }
+}
+
+// individual daily variables for .daily_variables()
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DailyVariable {
+    Weathercode,
+    Temperature2mMax,
+    Temperature2mMin,
+    ApparentTemperatureMax,
+    ApparentTemperatureMin,
+    Sunrise,
+    Sunset,
+    UvIndexMax,
+    UvIndexClearSkyMax,
+    PrecipitationSum,
+    RainSum,
+    ShowersSum,
+    SnowfallSum,
+    PrecipitationHours,
+    PrecipitationProbabilityMax,
+    Windspeed10mMax,
+    Windgusts10mMax,
+    Winddirection10mDominant,
+    ShortwaveRadiationSum,
+    Et0FaoEvapotranspiration,
+}
+
+impl DailyVariable {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DailyVariable::Weathercode => "weathercode",
+            DailyVariable::Temperature2mMax => "temperature_2m_max",
+            DailyVariable::Temperature2mMin => "temperature_2m_min",
+            DailyVariable::ApparentTemperatureMax => "apparent_temperature_max",
+            DailyVariable::ApparentTemperatureMin => "apparent_temperature_min",
+            DailyVariable::Sunrise => "sunrise",
+            DailyVariable::Sunset => "sunset",
+            DailyVariable::UvIndexMax => "uv_index_max",
+            DailyVariable::UvIndexClearSkyMax => "uv_index_clear_sky_max",
+            DailyVariable::PrecipitationSum => "precipitation_sum",
+            DailyVariable::RainSum => "rain_sum",
+            DailyVariable::ShowersSum => "showers_sum",
+            DailyVariable::SnowfallSum => "snowfall_sum",
+            DailyVariable::PrecipitationHours => "precipitation_hours",
+            DailyVariable::PrecipitationProbabilityMax => "precipitation_probability_max",
+            DailyVariable::Windspeed10mMax => "windspeed_10m_max",
+            DailyVariable::Windgusts10mMax => "windgusts_10m_max",
+            DailyVariable::Winddirection10mDominant => "winddirection_10m_dominant",
+            DailyVariable::ShortwaveRadiationSum => "shortwave_radiation_sum",
+            DailyVariable::Et0FaoEvapotranspiration => "et0_fao_evapotranspiration",
+        }
+    }
+}
+
+// top level response from the forecast api
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OpenMeteoData {
+    pub latitude: f32,
+    pub longitude: f32,
+    pub generationtime_ms: f32,
+    pub utc_offset_seconds: i32,
+    pub timezone: String,
+    pub timezone_abbreviation: String,
+    pub elevation: f32,
+    pub current_weather: Option<CurrentWeather>,
+    pub hourly_units: Option<HourlyUnits>,
+    pub hourly: Option<HourlyData>,
+    pub daily_units: Option<DailyUnits>,
+    pub daily: Option<DailyData>,
+}
+
+// error returned by the api when a request is invalid
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OpenMeteoError {
+    pub error: bool,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CurrentWeather {
+    pub temperature: f32,
+    pub windspeed: f32,
+    pub winddirection: f32,
+    pub weathercode: u8,
+    pub is_day: u8,
+    pub time: String,
+}
+
+// every field besides `time` is optional: .hourly_variables() only requests a
+// subset, and the api response only contains keys for the variables asked for
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HourlyUnits {
+    pub time: String,
+    #[serde(default)]
+    pub temperature_2m: Option<String>,
+    #[serde(default)]
+    pub relativehumidity_2m: Option<String>,
+    #[serde(default)]
+    pub dewpoint_2m: Option<String>,
+    #[serde(default)]
+    pub apparent_temperature: Option<String>,
+    #[serde(default)]
+    pub precipitation_probability: Option<String>,
+    #[serde(default)]
+    pub precipitation: Option<String>,
+    #[serde(default)]
+    pub rain: Option<String>,
+    #[serde(default)]
+    pub showers: Option<String>,
+    #[serde(default)]
+    pub snowfall: Option<String>,
+    #[serde(default)]
+    pub snow_depth: Option<String>,
+    #[serde(default)]
+    pub weathercode: Option<String>,
+    #[serde(default)]
+    pub pressure_msl: Option<String>,
+    #[serde(default)]
+    pub surface_pressure: Option<String>,
+    #[serde(default)]
+    pub cloudcover: Option<String>,
+    #[serde(default)]
+    pub cloudcover_low: Option<String>,
+    #[serde(default)]
+    pub cloudcover_mid: Option<String>,
+    #[serde(default)]
+    pub cloudcover_high: Option<String>,
+    #[serde(default)]
+    pub visibility: Option<String>,
+    #[serde(default)]
+    pub evapotranspiration: Option<String>,
+    #[serde(default)]
+    pub et0_fao_evapotranspiration: Option<String>,
+    #[serde(default)]
+    pub vapor_pressure_deficit: Option<String>,
+    #[serde(default)]
+    pub windspeed_10m: Option<String>,
+    #[serde(default)]
+    pub windspeed_80m: Option<String>,
+    #[serde(default)]
+    pub windspeed_120m: Option<String>,
+    #[serde(default)]
+    pub windspeed_180m: Option<String>,
+    #[serde(default)]
+    pub winddirection_10m: Option<String>,
+    #[serde(default)]
+    pub winddirection_80m: Option<String>,
+    #[serde(default)]
+    pub winddirection_120m: Option<String>,
+    #[serde(default)]
+    pub winddirection_180m: Option<String>,
+    #[serde(default)]
+    pub windgusts_10m: Option<String>,
+    #[serde(default)]
+    pub temperature_80m: Option<String>,
+    #[serde(default)]
+    pub temperature_120m: Option<String>,
+    #[serde(default)]
+    pub temperature_180m: Option<String>,
+    #[serde(default)]
+    pub soil_temperature_0cm: Option<String>,
+    #[serde(default)]
+    pub soil_temperature_6cm: Option<String>,
+    #[serde(default)]
+    pub soil_temperature_18cm: Option<String>,
+    #[serde(default)]
+    pub soil_temperature_54cm: Option<String>,
+    #[serde(default)]
+    pub soil_moisture_0_1cm: Option<String>,
+    #[serde(default)]
+    pub soil_moisture_1_3cm: Option<String>,
+    #[serde(default)]
+    pub soil_moisture_3_9cm: Option<String>,
+    #[serde(default)]
+    pub soil_moisture_9_27cm: Option<String>,
+    #[serde(default)]
+    pub soil_moisture_27_81cm: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HourlyData {
+    pub time: Vec<String>,
+    #[serde(default)]
+    pub temperature_2m: Vec<Option<f32>>,
+    #[serde(default)]
+    pub relativehumidity_2m: Vec<Option<f32>>,
+    #[serde(default)]
+    pub dewpoint_2m: Vec<Option<f32>>,
+    #[serde(default)]
+    pub apparent_temperature: Vec<Option<f32>>,
+    #[serde(default)]
+    pub precipitation_probability: Vec<Option<f32>>,
+    #[serde(default)]
+    pub precipitation: Vec<Option<f32>>,
+    #[serde(default)]
+    pub rain: Vec<Option<f32>>,
+    #[serde(default)]
+    pub showers: Vec<Option<f32>>,
+    #[serde(default)]
+    pub snowfall: Vec<Option<f32>>,
+    #[serde(default)]
+    pub snow_depth: Vec<Option<f32>>,
+    #[serde(default)]
+    pub weathercode: Vec<Option<u8>>,
+    #[serde(default)]
+    pub pressure_msl: Vec<Option<f32>>,
+    #[serde(default)]
+    pub surface_pressure: Vec<Option<f32>>,
+    #[serde(default)]
+    pub cloudcover: Vec<Option<f32>>,
+    #[serde(default)]
+    pub cloudcover_low: Vec<Option<f32>>,
+    #[serde(default)]
+    pub cloudcover_mid: Vec<Option<f32>>,
+    #[serde(default)]
+    pub cloudcover_high: Vec<Option<f32>>,
+    #[serde(default)]
+    pub visibility: Vec<Option<f32>>,
+    #[serde(default)]
+    pub evapotranspiration: Vec<Option<f32>>,
+    #[serde(default)]
+    pub et0_fao_evapotranspiration: Vec<Option<f32>>,
+    #[serde(default)]
+    pub vapor_pressure_deficit: Vec<Option<f32>>,
+    #[serde(default)]
+    pub windspeed_10m: Vec<Option<f32>>,
+    #[serde(default)]
+    pub windspeed_80m: Vec<Option<f32>>,
+    #[serde(default)]
+    pub windspeed_120m: Vec<Option<f32>>,
+    #[serde(default)]
+    pub windspeed_180m: Vec<Option<f32>>,
+    #[serde(default)]
+    pub winddirection_10m: Vec<Option<f32>>,
+    #[serde(default)]
+    pub winddirection_80m: Vec<Option<f32>>,
+    #[serde(default)]
+    pub winddirection_120m: Vec<Option<f32>>,
+    #[serde(default)]
+    pub winddirection_180m: Vec<Option<f32>>,
+    #[serde(default)]
+    pub windgusts_10m: Vec<Option<f32>>,
+    #[serde(default)]
+    pub temperature_80m: Vec<Option<f32>>,
+    #[serde(default)]
+    pub temperature_120m: Vec<Option<f32>>,
+    #[serde(default)]
+    pub temperature_180m: Vec<Option<f32>>,
+    #[serde(default)]
+    pub soil_temperature_0cm: Vec<Option<f32>>,
+    #[serde(default)]
+    pub soil_temperature_6cm: Vec<Option<f32>>,
+    #[serde(default)]
+    pub soil_temperature_18cm: Vec<Option<f32>>,
+    #[serde(default)]
+    pub soil_temperature_54cm: Vec<Option<f32>>,
+    #[serde(default)]
+    pub soil_moisture_0_1cm: Vec<Option<f32>>,
+    #[serde(default)]
+    pub soil_moisture_1_3cm: Vec<Option<f32>>,
+    #[serde(default)]
+    pub soil_moisture_3_9cm: Vec<Option<f32>>,
+    #[serde(default)]
+    pub soil_moisture_9_27cm: Vec<Option<f32>>,
+    #[serde(default)]
+    pub soil_moisture_27_81cm: Vec<Option<f32>>,
+}
+
+// every field besides `time` is optional: .daily_variables() only requests a
+// subset, and the api response only contains keys for the variables asked for
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DailyUnits {
+    pub time: String,
+    #[serde(default)]
+    pub weathercode: Option<String>,
+    #[serde(default)]
+    pub temperature_2m_max: Option<String>,
+    #[serde(default)]
+    pub temperature_2m_min: Option<String>,
+    #[serde(default)]
+    pub apparent_temperature_max: Option<String>,
+    #[serde(default)]
+    pub apparent_temperature_min: Option<String>,
+    #[serde(default)]
+    pub sunrise: Option<String>,
+    #[serde(default)]
+    pub sunset: Option<String>,
+    #[serde(default)]
+    pub uv_index_max: Option<String>,
+    #[serde(default)]
+    pub uv_index_clear_sky_max: Option<String>,
+    #[serde(default)]
+    pub precipitation_sum: Option<String>,
+    #[serde(default)]
+    pub rain_sum: Option<String>,
+    #[serde(default)]
+    pub showers_sum: Option<String>,
+    #[serde(default)]
+    pub snowfall_sum: Option<String>,
+    #[serde(default)]
+    pub precipitation_hours: Option<String>,
+    #[serde(default)]
+    pub precipitation_probability_max: Option<String>,
+    #[serde(default)]
+    pub windspeed_10m_max: Option<String>,
+    #[serde(default)]
+    pub windgusts_10m_max: Option<String>,
+    #[serde(default)]
+    pub winddirection_10m_dominant: Option<String>,
+    #[serde(default)]
+    pub shortwave_radiation_sum: Option<String>,
+    #[serde(default)]
+    pub et0_fao_evapotranspiration: Option<String>,
+}
+
+// response from the air-quality-api.open-meteo.com endpoint
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AirQualityData {
+    pub latitude: f32,
+    pub longitude: f32,
+    pub generationtime_ms: f32,
+    pub utc_offset_seconds: i32,
+    pub timezone: String,
+    pub timezone_abbreviation: String,
+    pub elevation: f32,
+    pub hourly_units: Option<AirQualityHourlyUnits>,
+    pub hourly: Option<AirQualityHourlyData>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AirQualityHourlyUnits {
+    pub time: String,
+    pub pm10: String,
+    pub pm2_5: String,
+    pub carbon_monoxide: String,
+    pub nitrogen_dioxide: String,
+    pub ozone: String,
+    pub sulphur_dioxide: String,
+    pub european_aqi: String,
+    pub us_aqi: String,
+    pub birch_pollen: String,
+    pub grass_pollen: String,
+    pub ragweed_pollen: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AirQualityHourlyData {
+    pub time: Vec<String>,
+    pub pm10: Vec<Option<f32>>,
+    pub pm2_5: Vec<Option<f32>>,
+    pub carbon_monoxide: Vec<Option<f32>>,
+    pub nitrogen_dioxide: Vec<Option<f32>>,
+    pub ozone: Vec<Option<f32>>,
+    pub sulphur_dioxide: Vec<Option<f32>>,
+    pub european_aqi: Vec<Option<u32>>,
+    pub us_aqi: Vec<Option<u32>>,
+    pub birch_pollen: Vec<Option<f32>>,
+    pub grass_pollen: Vec<Option<f32>>,
+    pub ragweed_pollen: Vec<Option<f32>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DailyData {
+    pub time: Vec<String>,
+    #[serde(default)]
+    pub weathercode: Vec<Option<u8>>,
+    #[serde(default)]
+    pub temperature_2m_max: Vec<Option<f32>>,
+    #[serde(default)]
+    pub temperature_2m_min: Vec<Option<f32>>,
+    #[serde(default)]
+    pub apparent_temperature_max: Vec<Option<f32>>,
+    #[serde(default)]
+    pub apparent_temperature_min: Vec<Option<f32>>,
+    #[serde(default)]
+    pub sunrise: Vec<Option<String>>,
+    #[serde(default)]
+    pub sunset: Vec<Option<String>>,
+    #[serde(default)]
+    pub uv_index_max: Vec<Option<f32>>,
+    #[serde(default)]
+    pub uv_index_clear_sky_max: Vec<Option<f32>>,
+    #[serde(default)]
+    pub precipitation_sum: Vec<Option<f32>>,
+    #[serde(default)]
+    pub rain_sum: Vec<Option<f32>>,
+    #[serde(default)]
+    pub showers_sum: Vec<Option<f32>>,
+    #[serde(default)]
+    pub snowfall_sum: Vec<Option<f32>>,
+    #[serde(default)]
+    pub precipitation_hours: Vec<Option<f32>>,
+    #[serde(default)]
+    pub precipitation_probability_max: Vec<Option<f32>>,
+    #[serde(default)]
+    pub windspeed_10m_max: Vec<Option<f32>>,
+    #[serde(default)]
+    pub windgusts_10m_max: Vec<Option<f32>>,
+    #[serde(default)]
+    pub winddirection_10m_dominant: Vec<Option<f32>>,
+    #[serde(default)]
+    pub shortwave_radiation_sum: Vec<Option<f32>>,
+    #[serde(default)]
+    pub et0_fao_evapotranspiration: Vec<Option<f32>>,
+}
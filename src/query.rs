@@ -1,422 +1,856 @@
-use std::error::Error;
-use serde_json::Value;
-
-use crate::models::*;
-
-#[derive(Debug)]
-pub struct OpenMeteo {
-    api_url: String,
-    set_coordinates: bool,
-    set_time_zone: bool,
-    set_start_date: bool,
-    set_end_date: bool,
-}
-
-impl OpenMeteo {
-
-    // create new instance of open-meteo
-
-    pub fn new() -> Self {
-        Self {
-            api_url: "https://api.open-meteo.com/v1/forecast?".to_owned(),
-            set_coordinates: false,
-            set_time_zone: false,
-            set_start_date: false,
-            set_end_date: false
-        }
-    }
-
-    // set coordinates 
-
-    pub fn coordinates(mut self, lat: f32, lon: f32) -> Result<OpenMeteo, Box<dyn Error>> {
-        if self.set_coordinates {
-            return Err("Location is already set".into());
-        }
-
-        let url_part = format!("latitude={}&longitude={}", lat, lon);
-        self.api_url.push_str(&url_part);
-        self.set_coordinates = true;
-
-        Ok(Self {
-            api_url: self.api_url,
-            set_coordinates: self.set_coordinates,
-            set_time_zone: self.set_time_zone,
-            set_start_date: self.set_start_date,
-            set_end_date: self.set_end_date,
-        })
-    }
-
-    // get coords using place name
-
-    pub async fn location(self, place_name: &str) -> Result<OpenMeteo, Box<dyn Error>> {
-        let url = format!("https://geocode.maps.co/search?q={}", place_name);
-
-        let mut response = reqwest::get(url).await?;
-
-        if response.status() != reqwest::StatusCode::OK {
-            return Err("Error getting city coordinates from geolocation".into());
-        } else {
-            response = response.text().await?
-        }
-
-        let json: Value = serde_json::from_str(&response).expect("Couldn't parse coordinates using geocode, try using .coordinates() instead".into());
-
-        let mut vec_len: usize = 0;
-
-        match json{
-            Value::Array(ref val) => vec_len = val.len(),
-            _ => {}
-        }
-        if vec_len < 1 {
-           return Err("Error getting city coordinates. Geolocation did not return any coordinates".into());
-        }
-
-        let (lat, lon) = 
-            (json[0]["lat"].as_str().unwrap()
-                .parse::<f32>().unwrap(), 
-             json[0]["lon"].as_str().unwrap()
-                .parse::<f32>().unwrap(),);
-
-        Ok(self.coordinates(lat, lon)?)
-    }
-
-    // check if location is not set
-
-    fn _check_location(&self) -> Result<(), Box<dyn Error>> {
-        if !self.set_coordinates {
-            return Err("Location is not set. Please set your location using .location() or .coordinates() method first.".into());
-        }
-        Ok(())
-    }
-
-    // set start date YYYY-MM-DD
-
-    pub fn start_date(mut self, start_date: &str) -> Result<OpenMeteo, Box<dyn Error>> {
-        if self.set_start_date {
-            return Err("Start date is already set".into());
-        }
-        self._check_location()?;
-
-        let url_part = format!("&start_date={}", start_date);
-        self.api_url.push_str(&url_part);
-        self.set_start_date = true;
-
-        Ok(Self {
-            api_url: self.api_url,
-            set_coordinates: self.set_coordinates,
-            set_time_zone: self.set_time_zone,
-            set_start_date: self.set_start_date,
-            set_end_date: self.set_end_date,
-        })
-    }
-
-    // set end date YYYY-MM-DD
-
-    pub fn end_date(mut self, end_date: &str) -> Result<OpenMeteo, Box<dyn Error>> {
-        if self.set_end_date {
-            return Err("End date is already set".into());
-        }
-        self._check_location()?;
-
-        let url_part = format!("&end_date={}", end_date);
-        self.api_url.push_str(&url_part);
-
-        self.set_end_date = true;
-
-        Ok(Self {
-            api_url: self.api_url,
-            set_coordinates: self.set_coordinates,
-            set_time_zone: self.set_time_zone,
-            set_start_date: self.set_start_date,
-            set_end_date: self.set_end_date,
-        })
-    }
-
-    // add current weather to request
-
-    pub fn current_weather(mut self) -> Result<OpenMeteo, Box<dyn Error>> {
-        self._check_location()?;
-        self.api_url.push_str("&current_weather=true");
-
-        Ok(Self {
-            api_url: self.api_url,
-            set_coordinates: self.set_coordinates,
-            set_time_zone: self.set_time_zone,
-            set_start_date: self.set_start_date,
-            set_end_date: self.set_end_date,
-        })
-    }
-
-    // add past days weather to request
-
-    pub fn past_days(mut self, past_days: u64) -> Result<OpenMeteo, Box<dyn Error>> {
-        self._check_location()?;
-
-        let url_part = format!("&past_days={}", past_days);
-        self.api_url.push_str(&url_part);
-
-        Ok(Self {
-            api_url: self.api_url,
-            set_coordinates: self.set_coordinates,
-            set_time_zone: self.set_time_zone,
-            set_start_date: self.set_start_date,
-            set_end_date: self.set_end_date,
-        })
-    }
-
-    // add forecast days weather to request
-
-    pub fn forecast_days(mut self, forecast_days: u64) -> Result<OpenMeteo, Box<dyn Error>> {
-        self._check_location()?;
-
-        let url_part = format!("&forecast_days={}", forecast_days);
-        self.api_url.push_str(&url_part);
-        
-        Ok(Self {
-            api_url: self.api_url,
-            set_coordinates: self.set_coordinates,
-            set_time_zone: self.set_time_zone,
-            set_start_date: self.set_start_date,
-            set_end_date: self.set_end_date,
-        })
-    }
-
-    // add all hourly variables to request
-
-    pub fn hourly(mut self) -> Result<OpenMeteo, Box<dyn Error>> {
-        self.api_url.push_str("&hourly=temperature_2m,relativehumidity_2m,dewpoint_2m,apparent_temperature,precipitation_probability,precipitation,rain,showers,snowfall,snow_depth,weathercode,pressure_msl,surface_pressure,cloudcover,cloudcover_low,cloudcover_mid,cloudcover_high,visibility,evapotranspiration,et0_fao_evapotranspiration,vapor_pressure_deficit,windspeed_10m,windspeed_80m,windspeed_120m,windspeed_180m,winddirection_10m,winddirection_80m,winddirection_120m,winddirection_180m,windgusts_10m,temperature_80m,temperature_120m,temperature_180m,soil_temperature_0cm,soil_temperature_6cm,soil_temperature_18cm,soil_temperature_54cm,soil_moisture_0_1cm,soil_moisture_1_3cm,soil_moisture_3_9cm,soil_moisture_9_27cm,soil_moisture_27_81cm");
-        Ok(Self {
-            api_url: self.api_url,
-            set_coordinates: self.set_coordinates,
-            set_time_zone: self.set_time_zone,
-            set_start_date: self.set_start_date,
-            set_end_date: self.set_end_date,
-        })
-    }
-
-    // add all daily variables to request
-
-    pub fn daily(mut self) -> Result<OpenMeteo, Box<dyn Error>> {
-        if !self.set_time_zone {
-            return Err("Specify .timezone() before .daily() method using TimeZone enum".into());
-        }
-        self.api_url.push_str("&daily=weathercode,temperature_2m_max,temperature_2m_min,apparent_temperature_max,apparent_temperature_min,sunrise,sunset,uv_index_max,uv_index_clear_sky_max,precipitation_sum,rain_sum,showers_sum,snowfall_sum,precipitation_hours,precipitation_probability_max,windspeed_10m_max,windgusts_10m_max,winddirection_10m_dominant,shortwave_radiation_sum,et0_fao_evapotranspiration");
-        
-        Ok(Self {
-            api_url: self.api_url,
-            set_coordinates: self.set_coordinates,
-            set_time_zone: self.set_time_zone,
-            set_start_date: self.set_start_date,
-            set_end_date: self.set_end_date,
-        })
-    }
-
-    // set time zone for daily variables
-
-    pub fn time_zone(mut self, time_zone: TimeZone) -> Result<OpenMeteo, Box<dyn Error>> {
-        if self.set_time_zone {
-            return Err("Time zone is already set".into());
-        }
-        self.api_url.push_str("&timezone=");
-        
-        match time_zone {
-            TimeZone::AmericaAnchorage => self.api_url.push_str("America%2FAnchorage"),
-            TimeZone::AmericaLosAngeles => self.api_url.push_str("America%2FLos_Angeles"),
-            TimeZone::AmericaDenver => self.api_url.push_str("America%2FDenver"),
-            TimeZone::AmericaChicago => self.api_url.push_str("America%2FChicago"),
-            TimeZone::AmericaNewYork => self.api_url.push_str("America%2FNew_York"),
-            TimeZone::AmericaSaoPaulo => self.api_url.push_str("America%2FASao_Paulo"),
-            TimeZone::GMT0 => self.api_url.push_str("GMT"),
-            TimeZone::Auto => self.api_url.push_str("auto"),
-            TimeZone::EuropeLondon => self.api_url.push_str("Europe%2FLondon"),
-            TimeZone::EuropeBerlin => self.api_url.push_str("Europe%2FBerlin"),
-            TimeZone::EuropeMoscow => self.api_url.push_str("Europe%2FMoscow"),
-            TimeZone::AfricaCairo => self.api_url.push_str("Africa%2FCairo"),
-            TimeZone::AsiaBangkok => self.api_url.push_str("Asia%2FBangkok"),
-            TimeZone::AsiaSingapore => self.api_url.push_str("Asia%2FSingapore"),
-            TimeZone::AsiaTokyo => self.api_url.push_str("Asia%2FTokio"),
-            TimeZone::AustraliaSydney => self.api_url.push_str("Australia%2FSydney"),
-            TimeZone::PacificAuckland => self.api_url.push_str("Pacific%2FAuckland")
-        }
-
-        self.set_time_zone = true;
-
-        Ok(Self {
-            api_url: self.api_url,
-            set_coordinates: self.set_coordinates,
-            set_time_zone: self.set_time_zone,
-            set_start_date: self.set_start_date,
-            set_end_date: self.set_end_date,
-        })
-    }
-
-    // send a request
-
-    pub async fn query(&self) -> Result<OpenMeteoData, Box<dyn Error>> {
-        let url = &self.api_url;
-        let response = reqwest::get(url).await?.text().await?;
-        let data = 
-            serde_json::from_str::<OpenMeteoData>(&response);
-        if data.is_err() { 
-            let err = 
-                serde_json::from_str::<OpenMeteoError>(&response)?;
-            return Err(err.reason.into());
-        }
-        Ok(data?)
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[tokio::test] 
-    async fn test_coordinates() {
-        let test = OpenMeteo::new()
-            .coordinates(51.0, 0.0).unwrap()
-            .query().await;
-
-        assert!(test.is_ok());
-    } 
-
-    #[tokio::test] 
-    async fn test_location() {
-        let data = OpenMeteo::new()
-            .location("Moscow").await.unwrap()
-            .query().await;
-
-        assert!(data.is_ok());
-    } 
-
-    #[tokio::test] 
-    async fn test_current_weather() {
-        let data = OpenMeteo::new()
-            .coordinates(55.0, 37.0).unwrap()
-            .current_weather().unwrap()
-            .hourly().unwrap()
-            .query().await;
-
-        assert!(data.is_ok());
-    } 
-
-    #[tokio::test] 
-    async fn test_past_days() {
-        let data = OpenMeteo::new()
-            .coordinates(55.0, 13.0).unwrap()
-            .past_days(10).unwrap()
-            .hourly().unwrap()
-            .query().await;
-
-        assert!(data.is_ok());
-    } 
-
-    #[tokio::test] 
-    async fn test_forecast_days() {
-        let data = OpenMeteo::new()
-            .coordinates(55.0, 13.0).unwrap()
-            .forecast_days(10).unwrap()
-            .hourly().unwrap()
-            .query().await;
-
-        assert!(data.is_ok());
-    } 
-
-    #[tokio::test] 
-    async fn location_not_set_error() {
-        let test1 = OpenMeteo::new()
-            .current_weather();
-        let test2 = OpenMeteo::new()
-            .past_days(10);
-        let test3 = OpenMeteo::new()
-            .forecast_days(10);
-        
-        eprintln!("{:?} .current_weather()", test1);  
-        eprintln!("{:?} .past_days()", test2); 
-        eprintln!("{:?} .forecast_days()", test3); 
-
-        assert!(test1.is_err());
-        assert!(test2.is_err());
-        assert!(test3.is_err());
-    } 
-
-    #[tokio::test] 
-    async fn location_is_already_set_error() {
-        let test1 = OpenMeteo::new()
-            .location("Berlin").await.unwrap()
-            .forecast_days(10).unwrap()
-            .location("Copenhagen").await;
-        let test2 = OpenMeteo::new()
-            .coordinates(55.0, 37.0).unwrap()
-            .forecast_days(10).unwrap()
-            .coordinates(55.0, 12.0);
-        let test3 = OpenMeteo::new()
-            .coordinates(55.0, 37.0).unwrap()
-            .forecast_days(10).unwrap()
-            .location("London").await;
-        eprintln!("{:?} double .location()", test1);
-        eprintln!("{:?} double .coordinates()", test2);
-        eprintln!("{:?} mixed", test3);   
-
-        assert!(test1.is_err());
-        assert!(test2.is_err());
-        assert!(test3.is_err());
-    } 
-
-    #[tokio::test] 
-    async fn test_daily() {
-        let test = OpenMeteo::new()
-            .location("London").await.unwrap()
-            .forecast_days(10).unwrap()
-            .time_zone(TimeZone::EuropeLondon).unwrap()
-            .daily();
-        
-        assert!(test.is_ok());
-    } 
-
-    #[tokio::test] 
-    async fn daily_without_timezone_error() {
-        let test = OpenMeteo::new()
-            .location("London").await.unwrap()
-            .forecast_days(10).unwrap()
-            .daily();
-        
-        eprintln!("{:?}", test);
-        assert!(test.is_err());
-    } 
-
-    #[tokio::test] 
-    async fn timezone_already_set_error() {
-        let test = OpenMeteo::new()
-            .location("London").await.unwrap()
-            .time_zone(TimeZone::EuropeLondon).unwrap()
-            .forecast_days(10).unwrap()
-            .daily().unwrap()
-            .time_zone(TimeZone::EuropeBerlin);
-
-        eprintln!("{:?}", test);    
-        assert!(test.is_err());
-    } 
-
-    #[tokio::test] 
-    async fn forecast_more_than_16_days_error()  {
-        let test = OpenMeteo::new()
-            .location("London").await.unwrap()
-            .forecast_days(17).unwrap()
-            .query().await;
-
-        eprintln!("{:?}", test);  
-        assert!(test.is_err());
-    }
-
-    #[tokio::test] 
-    async fn end_date_without_start_date_error()  {
-        let test = OpenMeteo::new()
-            .location("London").await.unwrap()
-            .current_weather().unwrap()
-            .end_date("2023-12-12").unwrap()
-            .query().await;
-
-        eprintln!("{:?}", test);  
-        assert!(test.is_err());
-    }
-}
+use std::error::Error;
+use serde_json::Value;
+use chrono_tz::Tz;
+
+use crate::models::*;
+
+#[derive(Debug)]
+pub struct OpenMeteo {
+    api_url: String,
+    set_coordinates: bool,
+    set_time_zone: bool,
+    set_start_date: bool,
+    set_end_date: bool,
+    set_temperature_unit: bool,
+    set_wind_speed_unit: bool,
+    set_precipitation_unit: bool,
+    batch_mode: bool,
+}
+
+impl OpenMeteo {
+
+    // create new instance of open-meteo
+
+    pub fn new() -> Self {
+        Self {
+            api_url: "https://api.open-meteo.com/v1/forecast?".to_owned(),
+            set_coordinates: false,
+            set_time_zone: false,
+            set_start_date: false,
+            set_end_date: false,
+            set_temperature_unit: false,
+            set_wind_speed_unit: false,
+            set_precipitation_unit: false,
+            batch_mode: false,
+        }
+    }
+
+    // create new instance of open-meteo pointed at the historical archive api,
+    // for start_date/end_date queries spanning further back than the forecast api allows
+
+    pub fn archive() -> Self {
+        Self {
+            api_url: "https://archive-api.open-meteo.com/v1/archive?".to_owned(),
+            set_coordinates: false,
+            set_time_zone: false,
+            set_start_date: false,
+            set_end_date: false,
+            set_temperature_unit: false,
+            set_wind_speed_unit: false,
+            set_precipitation_unit: false,
+            batch_mode: false,
+        }
+    }
+
+    // set coordinates
+
+    pub fn coordinates(mut self, lat: f32, lon: f32) -> Result<OpenMeteo, Box<dyn Error>> {
+        if self.set_coordinates {
+            return Err("Location is already set".into());
+        }
+
+        let url_part = format!("latitude={}&longitude={}", lat, lon);
+        self.api_url.push_str(&url_part);
+        self.set_coordinates = true;
+
+        Ok(self)
+    }
+
+    // set multiple coordinates for a single batched request,
+    // the api returns one result per location in the same order
+
+    pub fn locations(mut self, locations: &[(f32, f32)]) -> Result<OpenMeteo, Box<dyn Error>> {
+        if self.set_coordinates {
+            return Err("Location is already set".into());
+        }
+
+        let latitudes = locations.iter().map(|(lat, _)| lat.to_string()).collect::<Vec<String>>().join(",");
+        let longitudes = locations.iter().map(|(_, lon)| lon.to_string()).collect::<Vec<String>>().join(",");
+
+        let url_part = format!("latitude={}&longitude={}", latitudes, longitudes);
+        self.api_url.push_str(&url_part);
+        self.set_coordinates = true;
+        self.batch_mode = true;
+
+        Ok(self)
+    }
+
+    // get coords using place name
+
+    pub async fn location(self, place_name: &str) -> Result<OpenMeteo, Box<dyn Error>> {
+        let url = format!("https://geocode.maps.co/search?q={}", place_name);
+
+        let response = reqwest::get(url).await?;
+
+        if response.status() != reqwest::StatusCode::OK {
+            return Err("Error getting city coordinates from geolocation".into());
+        }
+
+        let response = response.text().await?;
+
+        let json: Value = serde_json::from_str(&response).expect("Couldn't parse coordinates using geocode, try using .coordinates() instead".into());
+
+        let mut vec_len: usize = 0;
+
+        match json{
+            Value::Array(ref val) => vec_len = val.len(),
+            _ => {}
+        }
+        if vec_len < 1 {
+           return Err("Error getting city coordinates. Geolocation did not return any coordinates".into());
+        }
+
+        let (lat, lon) = 
+            (json[0]["lat"].as_str().unwrap()
+                .parse::<f32>().unwrap(), 
+             json[0]["lon"].as_str().unwrap()
+                .parse::<f32>().unwrap(),);
+
+        Ok(self.coordinates(lat, lon)?)
+    }
+
+    // get coords from the caller's public ip address
+
+    pub async fn autolocate(self) -> Result<OpenMeteo, Box<dyn Error>> {
+        if self.set_coordinates {
+            return Err("Location is already set".into());
+        }
+
+        let url = "https://ipapi.co/json/";
+
+        let response = reqwest::get(url).await?;
+
+        if response.status() != reqwest::StatusCode::OK {
+            return Err("Error getting coordinates from ip address".into());
+        }
+
+        let text = response.text().await?;
+        let json: Value = serde_json::from_str(&text)
+            .map_err(|_| "Couldn't parse coordinates using ipapi, try using .coordinates() instead")?;
+
+        let lat = json["latitude"].as_f64();
+        let lon = json["longitude"].as_f64();
+
+        if lat.is_none() || lon.is_none() {
+            return Err("Error getting coordinates. Ip geolocation did not return any coordinates".into());
+        }
+
+        self.coordinates(lat.unwrap() as f32, lon.unwrap() as f32)
+    }
+
+    // check if location is not set
+
+    fn _check_location(&self) -> Result<(), Box<dyn Error>> {
+        if !self.set_coordinates {
+            return Err("Location is not set. Please set your location using .location() or .coordinates() method first.".into());
+        }
+        Ok(())
+    }
+
+    // set start date YYYY-MM-DD
+
+    pub fn start_date(mut self, start_date: &str) -> Result<OpenMeteo, Box<dyn Error>> {
+        if self.set_start_date {
+            return Err("Start date is already set".into());
+        }
+        self._check_location()?;
+
+        let url_part = format!("&start_date={}", start_date);
+        self.api_url.push_str(&url_part);
+        self.set_start_date = true;
+
+        Ok(self)
+    }
+
+    // set end date YYYY-MM-DD
+
+    pub fn end_date(mut self, end_date: &str) -> Result<OpenMeteo, Box<dyn Error>> {
+        if self.set_end_date {
+            return Err("End date is already set".into());
+        }
+        self._check_location()?;
+
+        let url_part = format!("&end_date={}", end_date);
+        self.api_url.push_str(&url_part);
+
+        self.set_end_date = true;
+
+        Ok(self)
+    }
+
+    // add current weather to request
+
+    pub fn current_weather(mut self) -> Result<OpenMeteo, Box<dyn Error>> {
+        self._check_location()?;
+        self.api_url.push_str("&current_weather=true");
+
+        Ok(self)
+    }
+
+    // add past days weather to request
+
+    pub fn past_days(mut self, past_days: u64) -> Result<OpenMeteo, Box<dyn Error>> {
+        self._check_location()?;
+
+        let url_part = format!("&past_days={}", past_days);
+        self.api_url.push_str(&url_part);
+
+        Ok(self)
+    }
+
+    // add forecast days weather to request
+
+    pub fn forecast_days(mut self, forecast_days: u64) -> Result<OpenMeteo, Box<dyn Error>> {
+        self._check_location()?;
+
+        let url_part = format!("&forecast_days={}", forecast_days);
+        self.api_url.push_str(&url_part);
+        
+        Ok(self)
+    }
+
+    // add all hourly variables to request
+
+    pub fn hourly(mut self) -> Result<OpenMeteo, Box<dyn Error>> {
+        self.api_url.push_str("&hourly=temperature_2m,relativehumidity_2m,dewpoint_2m,apparent_temperature,precipitation_probability,precipitation,rain,showers,snowfall,snow_depth,weathercode,pressure_msl,surface_pressure,cloudcover,cloudcover_low,cloudcover_mid,cloudcover_high,visibility,evapotranspiration,et0_fao_evapotranspiration,vapor_pressure_deficit,windspeed_10m,windspeed_80m,windspeed_120m,windspeed_180m,winddirection_10m,winddirection_80m,winddirection_120m,winddirection_180m,windgusts_10m,temperature_80m,temperature_120m,temperature_180m,soil_temperature_0cm,soil_temperature_6cm,soil_temperature_18cm,soil_temperature_54cm,soil_moisture_0_1cm,soil_moisture_1_3cm,soil_moisture_3_9cm,soil_moisture_9_27cm,soil_moisture_27_81cm");
+        Ok(self)
+    }
+
+    // add only the given hourly variables to request
+
+    pub fn hourly_variables(mut self, variables: &[HourlyVariable]) -> Result<OpenMeteo, Box<dyn Error>> {
+        let variables = variables.iter().map(|v| v.as_str()).collect::<Vec<&str>>().join(",");
+        self.api_url.push_str(&format!("&hourly={}", variables));
+
+        Ok(self)
+    }
+
+    // add all daily variables to request
+
+    pub fn daily(mut self) -> Result<OpenMeteo, Box<dyn Error>> {
+        if !self.set_time_zone {
+            return Err("Specify a time zone using .time_zone() or .time_zone_tz() before .daily()".into());
+        }
+        self.api_url.push_str("&daily=weathercode,temperature_2m_max,temperature_2m_min,apparent_temperature_max,apparent_temperature_min,sunrise,sunset,uv_index_max,uv_index_clear_sky_max,precipitation_sum,rain_sum,showers_sum,snowfall_sum,precipitation_hours,precipitation_probability_max,windspeed_10m_max,windgusts_10m_max,winddirection_10m_dominant,shortwave_radiation_sum,et0_fao_evapotranspiration");
+
+        Ok(self)
+    }
+
+    // add only the given daily variables to request
+
+    pub fn daily_variables(mut self, variables: &[DailyVariable]) -> Result<OpenMeteo, Box<dyn Error>> {
+        if !self.set_time_zone {
+            return Err("Specify a time zone using .time_zone() or .time_zone_tz() before .daily_variables()".into());
+        }
+        let variables = variables.iter().map(|v| v.as_str()).collect::<Vec<&str>>().join(",");
+        self.api_url.push_str(&format!("&daily={}", variables));
+
+        Ok(self)
+    }
+
+    // set time zone for daily variables
+
+    pub fn time_zone(mut self, time_zone: TimeZone) -> Result<OpenMeteo, Box<dyn Error>> {
+        if self.set_time_zone {
+            return Err("Time zone is already set".into());
+        }
+        self.api_url.push_str("&timezone=");
+        
+        match time_zone {
+            TimeZone::AmericaAnchorage => self.api_url.push_str("America%2FAnchorage"),
+            TimeZone::AmericaLosAngeles => self.api_url.push_str("America%2FLos_Angeles"),
+            TimeZone::AmericaDenver => self.api_url.push_str("America%2FDenver"),
+            TimeZone::AmericaChicago => self.api_url.push_str("America%2FChicago"),
+            TimeZone::AmericaNewYork => self.api_url.push_str("America%2FNew_York"),
+            TimeZone::AmericaSaoPaulo => self.api_url.push_str("America%2FSao_Paulo"),
+            TimeZone::GMT0 => self.api_url.push_str("GMT"),
+            TimeZone::Auto => self.api_url.push_str("auto"),
+            TimeZone::EuropeLondon => self.api_url.push_str("Europe%2FLondon"),
+            TimeZone::EuropeBerlin => self.api_url.push_str("Europe%2FBerlin"),
+            TimeZone::EuropeMoscow => self.api_url.push_str("Europe%2FMoscow"),
+            TimeZone::AfricaCairo => self.api_url.push_str("Africa%2FCairo"),
+            TimeZone::AsiaBangkok => self.api_url.push_str("Asia%2FBangkok"),
+            TimeZone::AsiaSingapore => self.api_url.push_str("Asia%2FSingapore"),
+            TimeZone::AsiaTokyo => self.api_url.push_str("Asia%2FTokyo"),
+            TimeZone::AustraliaSydney => self.api_url.push_str("Australia%2FSydney"),
+            TimeZone::PacificAuckland => self.api_url.push_str("Pacific%2FAuckland")
+        }
+
+        self.set_time_zone = true;
+
+        Ok(self)
+    }
+
+    // set time zone for daily variables using any IANA time zone from chrono-tz,
+    // covering zones the TimeZone enum does not have a variant for
+
+    pub fn time_zone_tz(mut self, time_zone: Tz) -> Result<OpenMeteo, Box<dyn Error>> {
+        if self.set_time_zone {
+            return Err("Time zone is already set".into());
+        }
+        let url_part = time_zone.name().replace('/', "%2F");
+        self.api_url.push_str(&format!("&timezone={}", url_part));
+
+        self.set_time_zone = true;
+
+        Ok(self)
+    }
+
+    // set temperature unit, defaults to celsius if not called
+
+    pub fn temperature_unit(mut self, temperature_unit: TemperatureUnit) -> Result<OpenMeteo, Box<dyn Error>> {
+        if self.set_temperature_unit {
+            return Err("Temperature unit is already set".into());
+        }
+        self._check_location()?;
+
+        let url_part = match temperature_unit {
+            TemperatureUnit::Celsius => "&temperature_unit=celsius",
+            TemperatureUnit::Fahrenheit => "&temperature_unit=fahrenheit",
+        };
+        self.api_url.push_str(url_part);
+        self.set_temperature_unit = true;
+
+        Ok(self)
+    }
+
+    // set wind speed unit, defaults to km/h if not called
+
+    pub fn wind_speed_unit(mut self, wind_speed_unit: WindSpeedUnit) -> Result<OpenMeteo, Box<dyn Error>> {
+        if self.set_wind_speed_unit {
+            return Err("Wind speed unit is already set".into());
+        }
+        self._check_location()?;
+
+        let url_part = match wind_speed_unit {
+            WindSpeedUnit::Kmh => "&windspeed_unit=kmh",
+            WindSpeedUnit::Ms => "&windspeed_unit=ms",
+            WindSpeedUnit::Mph => "&windspeed_unit=mph",
+            WindSpeedUnit::Kn => "&windspeed_unit=kn",
+        };
+        self.api_url.push_str(url_part);
+        self.set_wind_speed_unit = true;
+
+        Ok(self)
+    }
+
+    // set precipitation unit, defaults to millimeters if not called
+
+    pub fn precipitation_unit(mut self, precipitation_unit: PrecipitationUnit) -> Result<OpenMeteo, Box<dyn Error>> {
+        if self.set_precipitation_unit {
+            return Err("Precipitation unit is already set".into());
+        }
+        self._check_location()?;
+
+        let url_part = match precipitation_unit {
+            PrecipitationUnit::Mm => "&precipitation_unit=mm",
+            PrecipitationUnit::Inch => "&precipitation_unit=inch",
+        };
+        self.api_url.push_str(url_part);
+        self.set_precipitation_unit = true;
+
+        Ok(self)
+    }
+
+    // convenience shortcut setting temperature, wind speed and precipitation unit at once
+
+    pub fn units(self, unit_system: UnitSystem) -> Result<OpenMeteo, Box<dyn Error>> {
+        match unit_system {
+            UnitSystem::Metric => self
+                .temperature_unit(TemperatureUnit::Celsius)?
+                .wind_speed_unit(WindSpeedUnit::Kmh)?
+                .precipitation_unit(PrecipitationUnit::Mm),
+            UnitSystem::Imperial => self
+                .temperature_unit(TemperatureUnit::Fahrenheit)?
+                .wind_speed_unit(WindSpeedUnit::Mph)?
+                .precipitation_unit(PrecipitationUnit::Inch),
+        }
+    }
+
+    // send a request
+
+    pub async fn query(&self) -> Result<OpenMeteoData, Box<dyn Error>> {
+        if self.batch_mode {
+            return Err("Request was set up with .locations(), use .query_batch() instead".into());
+        }
+
+        let url = &self.api_url;
+        let response = reqwest::get(url).await?.text().await?;
+        let data =
+            serde_json::from_str::<OpenMeteoData>(&response);
+        if data.is_err() {
+            let err =
+                serde_json::from_str::<OpenMeteoError>(&response)?;
+            return Err(err.reason.into());
+        }
+        Ok(data?)
+    }
+
+    // send a request set up with .locations(), returning one result per location
+
+    pub async fn query_batch(&self) -> Result<Vec<OpenMeteoData>, Box<dyn Error>> {
+        if !self.batch_mode {
+            return Err("Request was set up with .coordinates(), use .query() instead".into());
+        }
+
+        let url = &self.api_url;
+        let response = reqwest::get(url).await?.text().await?;
+        let data =
+            serde_json::from_str::<Vec<OpenMeteoData>>(&response);
+        if data.is_err() {
+            let err =
+                serde_json::from_str::<OpenMeteoError>(&response)?;
+            return Err(err.reason.into());
+        }
+        Ok(data?)
+    }
+}
+
+// builder for the separate air-quality-api.open-meteo.com endpoint
+
+#[derive(Debug)]
+pub struct AirQuality {
+    api_url: String,
+    set_coordinates: bool,
+}
+
+impl AirQuality {
+
+    // create new instance of air quality
+
+    pub fn new() -> Self {
+        Self {
+            api_url: "https://air-quality-api.open-meteo.com/v1/air-quality?".to_owned(),
+            set_coordinates: false,
+        }
+    }
+
+    // set coordinates
+
+    pub fn coordinates(mut self, lat: f32, lon: f32) -> Result<AirQuality, Box<dyn Error>> {
+        if self.set_coordinates {
+            return Err("Location is already set".into());
+        }
+
+        let url_part = format!("latitude={}&longitude={}", lat, lon);
+        self.api_url.push_str(&url_part);
+        self.set_coordinates = true;
+
+        Ok(Self {
+            api_url: self.api_url,
+            set_coordinates: self.set_coordinates,
+        })
+    }
+
+    // check if location is not set
+
+    fn _check_location(&self) -> Result<(), Box<dyn Error>> {
+        if !self.set_coordinates {
+            return Err("Location is not set. Please set your location using .coordinates() method first.".into());
+        }
+        Ok(())
+    }
+
+    // add all hourly pollutant and pollen variables to request
+
+    pub fn hourly(mut self) -> Result<AirQuality, Box<dyn Error>> {
+        self._check_location()?;
+        self.api_url.push_str("&hourly=pm10,pm2_5,carbon_monoxide,nitrogen_dioxide,ozone,sulphur_dioxide,european_aqi,us_aqi,birch_pollen,grass_pollen,ragweed_pollen");
+
+        Ok(Self {
+            api_url: self.api_url,
+            set_coordinates: self.set_coordinates,
+        })
+    }
+
+    // send a request
+
+    pub async fn query(&self) -> Result<AirQualityData, Box<dyn Error>> {
+        let url = &self.api_url;
+        let response = reqwest::get(url).await?.text().await?;
+        let data =
+            serde_json::from_str::<AirQualityData>(&response);
+        if data.is_err() {
+            let err =
+                serde_json::from_str::<OpenMeteoError>(&response)?;
+            return Err(err.reason.into());
+        }
+        Ok(data?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_archive() {
+        let data = OpenMeteo::archive()
+            .coordinates(51.0, 0.0).unwrap()
+            .start_date("1990-01-01").unwrap()
+            .end_date("1990-01-10").unwrap()
+            .hourly().unwrap()
+            .query().await;
+
+        assert!(data.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_coordinates() {
+        let test = OpenMeteo::new()
+            .coordinates(51.0, 0.0).unwrap()
+            .query().await;
+
+        assert!(test.is_ok());
+    } 
+
+    #[tokio::test]
+    async fn test_locations_batch() {
+        let data = OpenMeteo::new()
+            .locations(&[(51.0, 0.0), (55.0, 37.0)]).unwrap()
+            .hourly().unwrap()
+            .query_batch().await;
+
+        assert!(data.is_ok());
+        assert_eq!(data.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn locations_already_set_error() {
+        let test = OpenMeteo::new()
+            .coordinates(51.0, 0.0).unwrap()
+            .locations(&[(55.0, 37.0)]);
+
+        eprintln!("{:?}", test);
+        assert!(test.is_err());
+    }
+
+    #[tokio::test]
+    async fn query_on_locations_request_error() {
+        let test = OpenMeteo::new()
+            .locations(&[(51.0, 0.0), (55.0, 37.0)]).unwrap()
+            .query().await;
+
+        eprintln!("{:?}", test);
+        assert!(test.is_err());
+    }
+
+    #[tokio::test]
+    async fn query_batch_on_coordinates_request_error() {
+        let test = OpenMeteo::new()
+            .coordinates(51.0, 0.0).unwrap()
+            .query_batch().await;
+
+        eprintln!("{:?}", test);
+        assert!(test.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_location() {
+        let data = OpenMeteo::new()
+            .location("Moscow").await.unwrap()
+            .query().await;
+
+        assert!(data.is_ok());
+    } 
+
+    #[tokio::test]
+    async fn test_autolocate() {
+        let data = OpenMeteo::new()
+            .autolocate().await.unwrap()
+            .query().await;
+
+        assert!(data.is_ok());
+    }
+
+    #[tokio::test]
+    async fn autolocate_location_already_set_error() {
+        let test = OpenMeteo::new()
+            .coordinates(55.0, 37.0).unwrap()
+            .autolocate().await;
+
+        eprintln!("{:?}", test);
+        assert!(test.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_current_weather() {
+        let data = OpenMeteo::new()
+            .coordinates(55.0, 37.0).unwrap()
+            .current_weather().unwrap()
+            .hourly().unwrap()
+            .query().await;
+
+        assert!(data.is_ok());
+    } 
+
+    #[tokio::test] 
+    async fn test_past_days() {
+        let data = OpenMeteo::new()
+            .coordinates(55.0, 13.0).unwrap()
+            .past_days(10).unwrap()
+            .hourly().unwrap()
+            .query().await;
+
+        assert!(data.is_ok());
+    } 
+
+    #[tokio::test] 
+    async fn test_forecast_days() {
+        let data = OpenMeteo::new()
+            .coordinates(55.0, 13.0).unwrap()
+            .forecast_days(10).unwrap()
+            .hourly().unwrap()
+            .query().await;
+
+        assert!(data.is_ok());
+    } 
+
+    #[tokio::test] 
+    async fn location_not_set_error() {
+        let test1 = OpenMeteo::new()
+            .current_weather();
+        let test2 = OpenMeteo::new()
+            .past_days(10);
+        let test3 = OpenMeteo::new()
+            .forecast_days(10);
+        
+        eprintln!("{:?} .current_weather()", test1);  
+        eprintln!("{:?} .past_days()", test2); 
+        eprintln!("{:?} .forecast_days()", test3); 
+
+        assert!(test1.is_err());
+        assert!(test2.is_err());
+        assert!(test3.is_err());
+    } 
+
+    #[tokio::test] 
+    async fn location_is_already_set_error() {
+        let test1 = OpenMeteo::new()
+            .location("Berlin").await.unwrap()
+            .forecast_days(10).unwrap()
+            .location("Copenhagen").await;
+        let test2 = OpenMeteo::new()
+            .coordinates(55.0, 37.0).unwrap()
+            .forecast_days(10).unwrap()
+            .coordinates(55.0, 12.0);
+        let test3 = OpenMeteo::new()
+            .coordinates(55.0, 37.0).unwrap()
+            .forecast_days(10).unwrap()
+            .location("London").await;
+        eprintln!("{:?} double .location()", test1);
+        eprintln!("{:?} double .coordinates()", test2);
+        eprintln!("{:?} mixed", test3);   
+
+        assert!(test1.is_err());
+        assert!(test2.is_err());
+        assert!(test3.is_err());
+    } 
+
+    #[tokio::test]
+    async fn test_hourly_variables() {
+        let data = OpenMeteo::new()
+            .coordinates(55.0, 37.0).unwrap()
+            .hourly_variables(&[HourlyVariable::Temperature2m, HourlyVariable::Precipitation]).unwrap()
+            .query().await;
+
+        assert!(data.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_daily_variables() {
+        let data = OpenMeteo::new()
+            .coordinates(55.0, 37.0).unwrap()
+            .time_zone(TimeZone::EuropeLondon).unwrap()
+            .daily_variables(&[DailyVariable::Temperature2mMax, DailyVariable::Sunrise]).unwrap()
+            .query().await;
+
+        assert!(data.is_ok());
+    }
+
+    #[tokio::test]
+    async fn daily_variables_without_timezone_error() {
+        let test = OpenMeteo::new()
+            .coordinates(55.0, 37.0).unwrap()
+            .daily_variables(&[DailyVariable::Temperature2mMax]);
+
+        eprintln!("{:?}", test);
+        assert!(test.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_daily() {
+        let test = OpenMeteo::new()
+            .location("London").await.unwrap()
+            .forecast_days(10).unwrap()
+            .time_zone(TimeZone::EuropeLondon).unwrap()
+            .daily();
+        
+        assert!(test.is_ok());
+    } 
+
+    #[tokio::test] 
+    async fn daily_without_timezone_error() {
+        let test = OpenMeteo::new()
+            .location("London").await.unwrap()
+            .forecast_days(10).unwrap()
+            .daily();
+        
+        eprintln!("{:?}", test);
+        assert!(test.is_err());
+    } 
+
+    #[tokio::test]
+    async fn test_time_zone_tz() {
+        let test = OpenMeteo::new()
+            .location("London").await.unwrap()
+            .forecast_days(10).unwrap()
+            .time_zone_tz(Tz::Asia__Tokyo).unwrap()
+            .daily();
+
+        assert!(test.is_ok());
+    }
+
+    #[tokio::test]
+    async fn time_zone_tz_already_set_error() {
+        let test = OpenMeteo::new()
+            .location("London").await.unwrap()
+            .time_zone_tz(Tz::Asia__Tokyo).unwrap()
+            .time_zone(TimeZone::EuropeBerlin);
+
+        eprintln!("{:?}", test);
+        assert!(test.is_err());
+    }
+
+    #[tokio::test]
+    async fn timezone_already_set_error() {
+        let test = OpenMeteo::new()
+            .location("London").await.unwrap()
+            .time_zone(TimeZone::EuropeLondon).unwrap()
+            .forecast_days(10).unwrap()
+            .daily().unwrap()
+            .time_zone(TimeZone::EuropeBerlin);
+
+        eprintln!("{:?}", test);    
+        assert!(test.is_err());
+    } 
+
+    #[tokio::test] 
+    async fn forecast_more_than_16_days_error()  {
+        let test = OpenMeteo::new()
+            .location("London").await.unwrap()
+            .forecast_days(17).unwrap()
+            .query().await;
+
+        eprintln!("{:?}", test);  
+        assert!(test.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_temperature_unit() {
+        let data = OpenMeteo::new()
+            .coordinates(55.0, 37.0).unwrap()
+            .temperature_unit(TemperatureUnit::Fahrenheit).unwrap()
+            .current_weather().unwrap()
+            .query().await;
+
+        assert!(data.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_units_imperial() {
+        let data = OpenMeteo::new()
+            .coordinates(55.0, 37.0).unwrap()
+            .units(UnitSystem::Imperial).unwrap()
+            .hourly().unwrap()
+            .query().await;
+
+        assert!(data.is_ok());
+    }
+
+    #[tokio::test]
+    async fn temperature_unit_already_set_error() {
+        let test = OpenMeteo::new()
+            .coordinates(55.0, 37.0).unwrap()
+            .temperature_unit(TemperatureUnit::Fahrenheit).unwrap()
+            .temperature_unit(TemperatureUnit::Celsius);
+
+        eprintln!("{:?}", test);
+        assert!(test.is_err());
+    }
+
+    #[tokio::test]
+    async fn wind_speed_unit_already_set_error() {
+        let test = OpenMeteo::new()
+            .coordinates(55.0, 37.0).unwrap()
+            .wind_speed_unit(WindSpeedUnit::Mph).unwrap()
+            .wind_speed_unit(WindSpeedUnit::Kmh);
+
+        eprintln!("{:?}", test);
+        assert!(test.is_err());
+    }
+
+    #[tokio::test]
+    async fn precipitation_unit_already_set_error() {
+        let test = OpenMeteo::new()
+            .coordinates(55.0, 37.0).unwrap()
+            .precipitation_unit(PrecipitationUnit::Inch).unwrap()
+            .precipitation_unit(PrecipitationUnit::Mm);
+
+        eprintln!("{:?}", test);
+        assert!(test.is_err());
+    }
+
+    #[tokio::test]
+    async fn units_already_set_error() {
+        let test = OpenMeteo::new()
+            .coordinates(55.0, 37.0).unwrap()
+            .units(UnitSystem::Metric).unwrap()
+            .units(UnitSystem::Imperial);
+
+        eprintln!("{:?}", test);
+        assert!(test.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_air_quality() {
+        let data = AirQuality::new()
+            .coordinates(55.0, 37.0).unwrap()
+            .hourly().unwrap()
+            .query().await;
+
+        assert!(data.is_ok());
+    }
+
+    #[tokio::test]
+    async fn air_quality_location_is_already_set_error() {
+        let test = AirQuality::new()
+            .coordinates(55.0, 37.0).unwrap()
+            .coordinates(55.0, 12.0);
+
+        eprintln!("{:?}", test);
+        assert!(test.is_err());
+    }
+
+    #[tokio::test]
+    async fn end_date_without_start_date_error()  {
+        let test = OpenMeteo::new()
+            .location("London").await.unwrap()
+            .current_weather().unwrap()
+            .end_date("2023-12-12").unwrap()
+            .query().await;
+
+        eprintln!("{:?}", test);  
+        assert!(test.is_err());
+    }
+}
@@ -1,5 +1,5 @@
 use std::error::Error;
-use open_meteo_api::query::OpenMeteo;
+use open_meteo_api::query::{OpenMeteo, AirQuality};
 use open_meteo_api::models::TimeZone;
 
 // how to use
@@ -41,6 +41,18 @@ async fn example() -> Result<(), Box<dyn Error>> {
 
     println!("{}", temperature );
     println!("{:?}", temperature_2m);
-        
+
+    // air quality uses its own builder pointed at the separate air-quality-api endpoint
+
+    let air_quality = AirQuality::new()
+            .coordinates(51.0, 0.0)?
+            .hourly()? // add hourly pollutant and pollen data
+            .query()
+            .await?;
+
+    let pm2_5 = air_quality.hourly.unwrap().pm2_5;
+
+    println!("{:?}", pm2_5);
+
     Ok(())
 }
\ No newline at end of file